@@ -0,0 +1,47 @@
+#![cfg(feature = "default-hasher")]
+
+use std::hash::{BuildHasher, Hasher};
+
+/// A [`Hasher`] tuned for randomly-generated [`Id`](crate::Id) keys.
+///
+/// Randomly-generated [`Id`](crate::Id) keys (as produced by `ErgoMap::insert` and
+/// `ErgoMap::insert_unchecked`) are already uniformly random 16-byte values, so the DoS
+/// resistance that [`RandomState`](std::collections::hash_map::RandomState) (SipHash) provides
+/// is wasted while its per-lookup cost is still paid on every `get`. This hasher instead reads
+/// the first 8 bytes of the key directly, with no further mixing, which is sound for those keys
+/// because their bytes are uniformly random and cannot be chosen by a caller.
+///
+/// This reasoning does **not** extend to `Id`s built from caller-controlled keys via
+/// `ErgoMap::insert_as`, `ErgoMap::force_insert_as`, or `BuildId::get_key` (`Key::Value`,
+/// `Key::Array`, `Key::Str`). Because `write` does no mixing, an attacker who controls those keys
+/// can pick values that collide in the low 8 bytes and reintroduce the hash-flooding DoS that
+/// `RandomState` exists to prevent. Do not enable the `default-hasher` feature for an `ErgoMap`
+/// that inserts values using attacker-influenced keys.
+#[derive(Debug, Default)]
+pub struct IdHasher(u64);
+
+impl Hasher for IdHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut buf = [0u8; 8];
+        let len = bytes.len().min(8);
+        buf[..len].copy_from_slice(&bytes[..len]);
+        self.0 = u64::from_ne_bytes(buf);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// [`BuildHasher`] for [`IdHasher`]. This is the [`ErgoMap`](crate::ErgoMap) default hasher when
+/// the `default-hasher` feature is enabled.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IdHasherBuilder;
+
+impl BuildHasher for IdHasherBuilder {
+    type Hasher = IdHasher;
+
+    fn build_hasher(&self) -> IdHasher {
+        IdHasher::default()
+    }
+}
@@ -22,13 +22,35 @@
 #![feature(split_array)]
 
 use rand::{thread_rng, Rng};
-use std::collections::hash_map::{Iter, IterMut, RandomState};
+use std::collections::hash_map::{self, Iter, IterMut};
+#[cfg(not(feature = "default-hasher"))]
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
+pub use std::collections::TryReserveError;
 use std::hash::{BuildHasher, Hash, Hasher};
 use std::marker::PhantomData;
 
+#[cfg(feature = "default-hasher")]
+mod id_hasher;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod tests;
 
+#[cfg(feature = "default-hasher")]
+pub use id_hasher::{IdHasher, IdHasherBuilder};
+
+/// The hash builder used by [`ErgoMap::new`] and [`ErgoMap::with_capacity`].
+///
+/// By default this is `RandomState`, the same SipHash-backed builder std's [`HashMap`] uses.
+/// Enabling the `default-hasher` feature switches this to [`IdHasherBuilder`], which is much
+/// cheaper per lookup since [`Id`] keys are already uniformly random.
+#[cfg(not(feature = "default-hasher"))]
+type DefaultHashBuilder = RandomState;
+#[cfg(feature = "default-hasher")]
+type DefaultHashBuilder = IdHasherBuilder;
+
 /// Map that wraps the std [`HashMap`], using [`Id`] as the key.
 ///
 /// When a value is inserted into the map, the corresponding [`Id`] is returned, which can be used
@@ -36,22 +58,22 @@ mod tests;
 /// key. This restricts the pool of possible [`Id`]s. Note that invalid [`Id`]s can still exist,
 /// either by removing values from the map or obtaining [`Id`]s from another map.
 #[derive(Debug, Default, Clone)]
-pub struct ErgoMap<T, S = RandomState> {
+pub struct ErgoMap<T, S = DefaultHashBuilder> {
     map: HashMap<Id<T>, T, S>,
 }
 
-impl<T> ErgoMap<T, RandomState> {
+impl<T> ErgoMap<T, DefaultHashBuilder> {
     /// Creates an empty `ErgoMap`.
     pub fn new() -> Self {
         ErgoMap {
-            map: HashMap::new(),
+            map: HashMap::with_hasher(DefaultHashBuilder::default()),
         }
     }
 
     /// Creates an empty `ErgoMap` with the specified capacity.
     pub fn with_capacity(capacity: usize) -> Self {
         ErgoMap {
-            map: HashMap::with_capacity(capacity),
+            map: HashMap::with_capacity_and_hasher(capacity, DefaultHashBuilder::default()),
         }
     }
 }
@@ -100,6 +122,37 @@ impl<T, S: BuildHasher> ErgoMap<T, S> {
         self.map.clear()
     }
 
+    /// Reserves capacity for at least `additional` more elements to be inserted in the map.
+    ///
+    /// # Panics
+    /// Panics if the new allocation size overflows `usize`.
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be inserted in the
+    /// map, returning an error if the capacity overflows `usize` or the allocator reports a
+    /// failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.map.try_reserve(additional)
+    }
+
+    /// Shrinks the capacity of the map as much as possible. It will drop down as much as possible
+    /// while maintaining the internal rules and possibly leaving some space in accordance with
+    /// the resize policy.
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity of the map with a lower limit. It will drop down no lower than the
+    /// supplied limit while maintaining the internal rules and possibly leaving some space in
+    /// accordance with the resize policy.
+    ///
+    /// If the current capacity is less than the lower limit, this is a no-op.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.map.shrink_to(min_capacity)
+    }
+
     /// Inserts a value into the map and returns the [`Id`] that can be used to access it.
     pub fn insert(&mut self, value: T) -> Id<T> {
         let id = Id::new_for(self);
@@ -108,6 +161,29 @@ impl<T, S: BuildHasher> ErgoMap<T, S> {
         id
     }
 
+    /// Inserts a value into the map with a randomly generated [`Id`], without checking whether
+    /// that [`Id`] is already in use.
+    ///
+    /// Unlike `insert`, this skips the loop that re-rolls the [`Id`] on a collision, trading an
+    /// astronomically unlikely collision (n in 2^128 for a map holding n values) for one fewer
+    /// lookup per insert. On a collision the existing value is replaced. Intended
+    /// for bulk-loading large numbers of values where that lookup shows up on a profile.
+    pub fn insert_unchecked(&mut self, value: T) -> Id<T> {
+        let id = Id::new(Key::Random);
+        self.map.insert(id, value);
+        id
+    }
+
+    /// Bulk variant of `insert_unchecked`. Inserts every value from the given iterator, each with
+    /// its own randomly generated, uncollision-checked [`Id`], and returns the generated [`Id`]s
+    /// in the same order as the input values.
+    pub fn extend_unchecked<I: IntoIterator<Item = T>>(&mut self, values: I) -> Vec<Id<T>> {
+        values
+            .into_iter()
+            .map(|value| self.insert_unchecked(value))
+            .collect()
+    }
+
     /// Inserts a value into the map, using the specified key [`Vec`] to make an [`Id`] for it.
     ///
     /// Returns [`None`] if that [`Id`] is already in use. Otherwise returns that [`Id`].
@@ -136,6 +212,31 @@ impl<T, S: BuildHasher> ErgoMap<T, S> {
         self.map.remove(id)
     }
 
+    /// Retains only the id-value pairs for which the given function returns `true`, removing the
+    /// rest.
+    pub fn retain<F: FnMut(&Id<T>, &mut T) -> bool>(&mut self, f: F) {
+        self.map.retain(f)
+    }
+
+    /// Clears the map, returning all id-value pairs as an iterator. Keeps the allocated memory
+    /// for reuse.
+    pub fn drain(&mut self) -> hash_map::Drain<'_, Id<T>, T> {
+        self.map.drain()
+    }
+
+    /// Creates an iterator which uses the given function to decide whether to remove an
+    /// id-value pair. If the function returns `true`, the pair is removed from the map and
+    /// yielded. If it returns `false`, the pair remains in the map and is not yielded.
+    ///
+    /// Pairs are removed lazily as the iterator is driven; if the iterator is dropped before
+    /// being fully consumed, the remaining matching pairs stay in the map.
+    pub fn extract_if<F: FnMut(&Id<T>, &mut T) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> hash_map::ExtractIf<'_, Id<T>, T, F> {
+        self.map.extract_if(pred)
+    }
+
     /// Returns `true` if the map contains a value with the specified [`Id`].
     pub fn contains_id(&self, id: &Id<T>) -> bool {
         self.map.contains_key(id)
@@ -169,6 +270,20 @@ impl<T, S: BuildHasher> ErgoMap<T, S> {
         self.map.get_mut(id)
     }
 
+    /// Gets the given [`Id`]'s corresponding entry in the map for in-place manipulation.
+    ///
+    /// Unlike using `contains_id` followed by `get_mut` or `force_insert_as`, this only performs
+    /// a single lookup.
+    pub fn entry(&mut self, id: Id<T>) -> Entry<'_, T, S> {
+        match self.map.entry(id) {
+            hash_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry { inner: entry }),
+            hash_map::Entry::Vacant(entry) => Entry::Vacant(VacantEntry {
+                inner: entry,
+                phantom: PhantomData,
+            }),
+        }
+    }
+
     /// Calls the given function on the corresponding value to the specified [`Id`].
     pub fn for_one<R, F: FnOnce(&T) -> R>(&self, id: &Id<T>, f: F) -> Option<R> {
         self.map.get(id).map(f)
@@ -245,6 +360,67 @@ impl<T: BuildId, S: BuildHasher> ErgoMap<T, S> {
     }
 }
 
+/// A view into a single entry in an [`ErgoMap`], which may either be vacant or occupied.
+///
+/// This enum is constructed by [`ErgoMap::entry`]. See its documentation for more.
+pub enum Entry<'a, T, S = DefaultHashBuilder> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, T>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, T, S>),
+}
+
+impl<'a, T, S> Entry<'a, T, S> {
+    /// Returns the [`Id`] of this entry.
+    pub fn id(&self) -> Id<T> {
+        match self {
+            Entry::Occupied(entry) => *entry.inner.key(),
+            Entry::Vacant(entry) => *entry.inner.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if empty, then returns a mutable
+    /// reference to the value.
+    pub fn or_insert(self, default: T) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.inner.into_mut(),
+            Entry::Vacant(entry) => entry.inner.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of `default` if empty, then
+    /// returns a mutable reference to the value.
+    pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+        match self {
+            Entry::Occupied(entry) => entry.inner.into_mut(),
+            Entry::Vacant(entry) => entry.inner.insert(default()),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any potential inserts into
+    /// the map.
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.inner.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+/// A view into an occupied entry in an [`ErgoMap`]. Part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, T> {
+    inner: hash_map::OccupiedEntry<'a, Id<T>, T>,
+}
+
+/// A view into a vacant entry in an [`ErgoMap`]. Part of the [`Entry`] enum.
+pub struct VacantEntry<'a, T, S = DefaultHashBuilder> {
+    inner: hash_map::VacantEntry<'a, Id<T>, T>,
+    phantom: PhantomData<S>,
+}
+
 /// Key used to access values in an [`ErgoMap`].
 ///
 /// Constructors are made private to reduce the amount of invalid `get` calls. Note that `get` can
@@ -0,0 +1,29 @@
+#![cfg(feature = "rayon")]
+
+use crate::{ErgoMap, Id};
+use rayon::collections::hash_map::{Iter, IterMut};
+use rayon::iter::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator};
+use std::hash::BuildHasher;
+
+impl<T: Send + Sync, S: BuildHasher> ErgoMap<T, S> {
+    /// Returns a parallel iterator visiting all id-value pairs, in arbitrary order.
+    pub fn par_iter(&self) -> Iter<'_, Id<T>, T> {
+        self.map.par_iter()
+    }
+
+    /// Returns a mutable parallel iterator visiting all id-value pairs, in arbitrary order.
+    pub fn par_iter_mut(&mut self) -> IterMut<'_, Id<T>, T> {
+        self.map.par_iter_mut()
+    }
+
+    /// Calls the given function, in parallel, on every id-value pair in the map.
+    pub fn par_for_all<F: Fn(&Id<T>, &T) + Sync + Send>(&self, f: F) {
+        self.par_iter().for_each(|(id, value)| f(id, value))
+    }
+
+    /// Calls the given function, in parallel, on every id-value pair in the map. Provides a
+    /// mutable reference to values.
+    pub fn par_for_all_mut<F: Fn(&Id<T>, &mut T) + Sync + Send>(&mut self, f: F) {
+        self.par_iter_mut().for_each(|(id, value)| f(id, value))
+    }
+}
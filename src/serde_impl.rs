@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use crate::{ErgoMap, Id};
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+
+impl<T> Serialize for Id<T> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.key.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Id<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = <[u8; 16]>::deserialize(deserializer)?;
+
+        Ok(Id {
+            key,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Serialize, S> Serialize for ErgoMap<T, S> {
+    /// Serializes the map as a sequence of id-value pairs. Because `Id`s are stable 16-byte keys
+    /// rather than opaque handles, an `Id` deserialized elsewhere will still resolve against this
+    /// map after a round-trip.
+    ///
+    /// This is serialized as a sequence rather than a map because an `Id` serializes as a raw
+    /// `[u8; 16]`, which self-describing formats like JSON cannot use as a map key.
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.map.iter())
+    }
+}
+
+impl<'de, T, S> Deserialize<'de> for ErgoMap<T, S>
+where
+    T: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ErgoMapVisitor<T, S> {
+            marker: PhantomData<(T, S)>,
+        }
+
+        impl<'de, T, S> Visitor<'de> for ErgoMapVisitor<T, S>
+        where
+            T: Deserialize<'de>,
+            S: BuildHasher + Default,
+        {
+            type Value = ErgoMap<T, S>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of Id-value pairs")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut map =
+                    ErgoMap::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), S::default());
+
+                while let Some((id, value)) = seq.next_element()? {
+                    map.map.insert(id, value);
+                }
+
+                Ok(map)
+            }
+        }
+
+        deserializer.deserialize_seq(ErgoMapVisitor {
+            marker: PhantomData,
+        })
+    }
+}
@@ -144,6 +144,218 @@ fn ergomap_force_insert_as() {
     assert!(map.try_get(&id).unwrap());
 }
 
+#[test]
+fn ergomap_entry_or_insert_vacant() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    let id = map.insert(1);
+    map.remove(&id);
+
+    *map.entry(id).or_insert(5) += 1;
+    assert_eq!(*map.try_get(&id).unwrap(), 6);
+}
+
+#[test]
+fn ergomap_entry_or_insert_occupied() {
+    let mut map = ErgoMap::new();
+    let id = map.insert(1);
+
+    *map.entry(id).or_insert(5) += 1;
+    assert_eq!(*map.try_get(&id).unwrap(), 2);
+}
+
+#[test]
+fn ergomap_entry_or_insert_with() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    let id = map.insert(1);
+    map.remove(&id);
+
+    map.entry(id).or_insert_with(|| 42);
+    assert_eq!(*map.try_get(&id).unwrap(), 42);
+}
+
+#[test]
+fn ergomap_entry_and_modify() {
+    let mut map = ErgoMap::new();
+    let id = map.insert(1);
+
+    map.entry(id).and_modify(|value| *value += 1).or_insert(0);
+    assert_eq!(*map.try_get(&id).unwrap(), 2);
+}
+
+#[test]
+fn ergomap_entry_id() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    let id = map.insert(1);
+    assert_eq!(map.entry(id).id(), id);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn ergomap_serde_roundtrip() {
+    let mut map = ErgoMap::new();
+    let id1 = map.insert(1);
+    let id2 = map.insert(2);
+    let id3 = map.insert(3);
+
+    let json = serde_json::to_string(&map).unwrap();
+    let map: ErgoMap<i32> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(*map.try_get(&id1).unwrap(), 1);
+    assert_eq!(*map.try_get(&id2).unwrap(), 2);
+    assert_eq!(*map.try_get(&id3).unwrap(), 3);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn ergomap_serde_id_roundtrip() {
+    let mut map = ErgoMap::new();
+    let id = map.insert("hello".to_string());
+
+    let id_json = serde_json::to_string(&id).unwrap();
+    let id: Id<String> = serde_json::from_str(&id_json).unwrap();
+
+    assert_eq!(map.try_get(&id).unwrap(), "hello");
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn ergomap_par_for_all() {
+    use rayon::iter::ParallelIterator;
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    let mut map = ErgoMap::new();
+    map.insert(1);
+    map.insert(1);
+    map.insert(1);
+
+    let sum = AtomicI32::new(0);
+    map.par_for_all(|_, value| {
+        sum.fetch_add(*value, Ordering::Relaxed);
+    });
+    assert_eq!(sum.load(Ordering::Relaxed), 3);
+    assert_eq!(map.par_iter().count(), 3);
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn ergomap_par_for_all_mut() {
+    let mut map = ErgoMap::new();
+    let id1 = map.insert(1);
+    let id2 = map.insert(2);
+    let id3 = map.insert(3);
+
+    map.par_for_all_mut(|_, value| {
+        *value *= 10;
+    });
+
+    assert_eq!(*map.try_get(&id1).unwrap(), 10);
+    assert_eq!(*map.try_get(&id2).unwrap(), 20);
+    assert_eq!(*map.try_get(&id3).unwrap(), 30);
+}
+
+#[test]
+fn ergomap_reserve() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    map.reserve(64);
+    assert!(map.capacity() >= 64)
+}
+
+#[test]
+fn ergomap_try_reserve() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    assert!(map.try_reserve(64).is_ok());
+    assert!(map.capacity() >= 64)
+}
+
+#[test]
+fn ergomap_shrink_to_fit() {
+    let mut map = ErgoMap::with_capacity(64);
+    map.insert(1);
+    map.shrink_to_fit();
+    assert!(map.capacity() < 64)
+}
+
+#[test]
+fn ergomap_shrink_to() {
+    let mut map = ErgoMap::with_capacity(64);
+    map.insert(1);
+    map.shrink_to(16);
+    assert!(map.capacity() < 64)
+}
+
+#[test]
+fn ergomap_retain() {
+    let mut map = ErgoMap::new();
+    map.insert(1);
+    map.insert(2);
+    map.insert(3);
+    map.insert(4);
+
+    map.retain(|_, value| *value % 2 == 0);
+
+    assert_eq!(map.len(), 2);
+    map.for_all(|_, value| assert_eq!(value % 2, 0))
+}
+
+#[test]
+fn ergomap_drain() {
+    let mut map = ErgoMap::new();
+    map.insert(1);
+    map.insert(2);
+    map.insert(3);
+
+    let drained: Vec<i32> = map.drain().map(|(_, value)| value).collect();
+
+    assert!(map.is_empty());
+    assert_eq!(drained.len(), 3);
+    assert_eq!(drained.iter().sum::<i32>(), 6);
+}
+
+#[test]
+fn ergomap_extract_if() {
+    let mut map = ErgoMap::new();
+    map.insert(1);
+    map.insert(2);
+    map.insert(3);
+    map.insert(4);
+
+    let extracted: Vec<i32> = map.extract_if(|_, value| *value % 2 == 0).map(|(_, value)| value).collect();
+
+    assert_eq!(map.len(), 2);
+    assert_eq!(extracted.len(), 2);
+    assert_eq!(extracted.iter().sum::<i32>(), 6);
+}
+
+#[test]
+#[cfg(feature = "default-hasher")]
+fn ergomap_default_hasher() {
+    let mut map: ErgoMap<i32> = ErgoMap::new();
+    let id1 = map.insert(1);
+    let id2 = map.insert(2);
+
+    assert_eq!(*map.try_get(&id1).unwrap(), 1);
+    assert_eq!(*map.try_get(&id2).unwrap(), 2);
+}
+
+#[test]
+fn ergomap_insert_unchecked() {
+    let mut map = ErgoMap::new();
+    let id = map.insert_unchecked(1);
+    assert_eq!(*map.try_get(&id).unwrap(), 1);
+}
+
+#[test]
+fn ergomap_extend_unchecked() {
+    let mut map = ErgoMap::new();
+    let ids = map.extend_unchecked(vec![1, 2, 3]);
+
+    assert_eq!(ids.len(), 3);
+    assert_eq!(map.len(), 3);
+    assert_eq!(*map.try_get(&ids[0]).unwrap(), 1);
+    assert_eq!(*map.try_get(&ids[1]).unwrap(), 2);
+    assert_eq!(*map.try_get(&ids[2]).unwrap(), 3);
+}
+
 #[test]
 fn ergomap_build_insert() {
     impl BuildId for bool {